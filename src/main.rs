@@ -1,13 +1,21 @@
+use std::cell::RefCell;
 use std::ffi::c_int;
+use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Once;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::{process, ptr, thread};
 
 use alsa::mixer::{SelemChannelId, SelemId};
 use alsa::Mixer;
+use libpulse_binding::callbacks::ListResult;
+use libpulse_binding::context::{Context, FlagSet as ContextFlagSet, State as PulseState};
+use libpulse_binding::mainloop::standard::{IterateResult, Mainloop};
+use libpulse_binding::volume::Volume;
 use sys::*;
-use sysinfo::{CpuExt, System, SystemExt};
+use sysinfo::{
+    ComponentExt, ComponentsExt, CpuExt, DiskExt, NetworkExt, NetworksExt, System, SystemExt,
+};
 
 mod sys {
     use core::ffi::{c_char, c_int, c_ulong, c_void};
@@ -57,77 +65,621 @@ fn set_root_name(name: &str) {
     }
 }
 
+const DEFAULT_TIME_FMT: &str = "%m/%d %H:%M";
+
+/// A token that can appear in a format string; each maps to a cached field of
+/// [`Status`]. `time` additionally carries a `strftime` argument after a colon.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Cpu,
+    CpuBars,
+    Mem,
+    Vol,
+    Net,
+    Temp,
+    Disk,
+    Time,
+}
+
+impl Token {
+    fn parse(name: &str) -> Option<Token> {
+        match name {
+            "cpu" => Some(Token::Cpu),
+            "cpu_bars" => Some(Token::CpuBars),
+            "mem" => Some(Token::Mem),
+            "vol" => Some(Token::Vol),
+            "net" => Some(Token::Net),
+            "temp" => Some(Token::Temp),
+            "disk" => Some(Token::Disk),
+            "time" => Some(Token::Time),
+            _ => None,
+        }
+    }
+}
+
+/// A compiled format string: literal runs interleaved with token slots.
+enum Segment {
+    Lit(String),
+    Tok { token: Token, arg: Option<String> },
+}
+
+/// Split a user format string into literals and recognised `{token}` slots.
+/// An unrecognised `{...}` is kept verbatim so typos are visible in the bar
+/// rather than silently swallowed.
+fn parse_format(fmt: &str) -> Vec<Segment> {
+    let mut segs = Vec::new();
+    let mut lit = String::new();
+    let mut rest = fmt;
+    while let Some(open) = rest.find('{') {
+        let Some(close_rel) = rest[open + 1..].find('}') else {
+            break;
+        };
+        let close = open + 1 + close_rel;
+        let (name, arg) = match rest[open + 1..close].split_once(':') {
+            Some((n, a)) => (n, Some(a.to_string())),
+            None => (&rest[open + 1..close], None),
+        };
+        match Token::parse(name) {
+            Some(token) => {
+                lit.push_str(&rest[..open]);
+                if !lit.is_empty() {
+                    segs.push(Segment::Lit(std::mem::take(&mut lit)));
+                }
+                segs.push(Segment::Tok { token, arg });
+            }
+            None => lit.push_str(&rest[..=close]),
+        }
+        rest = &rest[close + 1..];
+    }
+    lit.push_str(rest);
+    if !lit.is_empty() {
+        segs.push(Segment::Lit(lit));
+    }
+    segs
+}
+
+mod config {
+    use serde::Deserialize;
+
+    pub const DEFAULT_FORMAT: &str = "[{cpu}|{mem}|{net}] {time:%m/%d %H:%M}";
+    pub const DEFAULT_FORMAT_FULL: &str =
+        "[{cpu}|{mem}|{net}|{temp}|{disk}] ({vol}) {time:%m/%d %H:%M}";
+
+    pub const DEFAULT_DISK_MOUNT: &str = "/";
+
+    /// Parsed `config.toml`. Missing keys fall back to the defaults below so a
+    /// partial (or absent) file still yields a usable bar.
+    #[derive(Deserialize)]
+    #[serde(default)]
+    pub struct Config {
+        pub format: String,
+        pub format_full: String,
+        /// Mount point the `{disk}` module reports on.
+        pub disk_mount: String,
+        pub intervals: Intervals,
+    }
+
+    impl Default for Config {
+        fn default() -> Self {
+            Self {
+                format: DEFAULT_FORMAT.to_string(),
+                format_full: DEFAULT_FORMAT_FULL.to_string(),
+                disk_mount: DEFAULT_DISK_MOUNT.to_string(),
+                intervals: Intervals::default(),
+            }
+        }
+    }
+
+    /// Per-module refresh period, in seconds.
+    #[derive(Deserialize)]
+    #[serde(default)]
+    pub struct Intervals {
+        pub cpu: u64,
+        pub mem: u64,
+        pub net: u64,
+        pub temp: u64,
+        pub disk: u64,
+        pub vol: u64,
+        pub time: u64,
+    }
+
+    impl Default for Intervals {
+        fn default() -> Self {
+            Self {
+                cpu: 3,
+                mem: 3,
+                net: 1,
+                temp: 3,
+                disk: 60,
+                vol: 1,
+                time: 60,
+            }
+        }
+    }
+
+    pub fn load() -> Config {
+        let Some(path) = config_path() else {
+            return Config::default();
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text).unwrap_or_else(|err| {
+                eprintln!("dwm-status: ignoring bad config {}: {}", path.display(), err);
+                Config::default()
+            }),
+            Err(_) => Config::default(),
+        }
+    }
+
+    fn config_path() -> Option<std::path::PathBuf> {
+        let base = std::env::var_os("XDG_CONFIG_HOME")
+            .map(std::path::PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME").map(|h| std::path::PathBuf::from(h).join(".config"))
+            })?;
+        Some(base.join("dwm-status").join("config.toml"))
+    }
+}
+
+/// Unix-timestamp deadlines for each cadence-driven module; a module is polled
+/// again once the current time reaches its deadline.
+#[derive(Default)]
+struct Due {
+    cpu: i64,
+    mem: i64,
+    net: i64,
+    temp: i64,
+    disk: i64,
+    vol: i64,
+    time: i64,
+}
+
 struct Status {
     time: String,
     sys_stat: System,
     cpu: String,
     mem: String,
     vol: String,
+    /// `None` when no format references `{vol}`, so the Pulse/ALSA backend is
+    /// never even connected on a bar that does not show volume.
+    vol_backend: Option<Box<dyn VolumeBackend>>,
+    cpu_bars: String,
+    net: String,
+    temp: String,
+    disk: String,
+    prev_rx: u64,
+    prev_tx: u64,
+    last_net_instant: Instant,
+    net_seeded: bool,
+    cfg: config::Config,
+    fmt_basic: Vec<Segment>,
+    fmt_full: Vec<Segment>,
+    time_fmt: String,
+    due: Due,
     should_update: bool,
 }
 
-fn load_snd_vol(status: &mut Status) {
-    status.vol = if let Ok(mixer) = Mixer::new("default", false) {
-        mixer
-            .find_selem(&SelemId::new("Master", 0))
-            .and_then(|master| {
-                let (vol_min, vol_max) = master.get_playback_volume_range();
-                let mut total = 0;
-                let mut cur = 0;
-                for c in SelemChannelId::all().iter() {
-                    if master.has_playback_channel(*c) {
-                        total += vol_max - vol_min;
-                        let sw = master.get_playback_switch(*c).ok()?;
-                        if sw == 0 {
-                            continue;
+/// Whether `token` appears anywhere in `segs`.
+fn uses_token(segs: &[Segment], token: Token) -> bool {
+    segs.iter()
+        .any(|s| matches!(s, Segment::Tok { token: t, .. } if *t == token))
+}
+
+impl Status {
+    /// Whether `token` appears in the format that is *currently displayed*.
+    /// Only such tokens are worth polling, so a module referenced solely in
+    /// `format_full` stays idle until full status is toggled on.
+    fn uses(&self, token: Token) -> bool {
+        let segs = if FULL_STATUS.load(Ordering::SeqCst) {
+            &self.fmt_full
+        } else {
+            &self.fmt_basic
+        };
+        uses_token(segs, token)
+    }
+
+    fn render(&self, segs: &[Segment]) -> String {
+        let mut out = String::new();
+        for seg in segs {
+            match seg {
+                Segment::Lit(s) => out.push_str(s),
+                Segment::Tok { token, .. } => out.push_str(match token {
+                    Token::Cpu => &self.cpu,
+                    Token::CpuBars => &self.cpu_bars,
+                    Token::Mem => &self.mem,
+                    Token::Vol => &self.vol,
+                    Token::Net => &self.net,
+                    Token::Temp => &self.temp,
+                    Token::Disk => &self.disk,
+                    Token::Time => &self.time,
+                }),
+            }
+        }
+        out
+    }
+}
+
+/// Pull the `strftime` argument out of the first `{time:..}` token found in
+/// either format, defaulting to [`DEFAULT_TIME_FMT`].
+fn resolve_time_fmt(formats: &[&[Segment]]) -> String {
+    for segs in formats {
+        for seg in *segs {
+            if let Segment::Tok {
+                token: Token::Time,
+                arg: Some(fmt),
+            } = seg
+            {
+                return fmt.clone();
+            }
+        }
+    }
+    DEFAULT_TIME_FMT.to_string()
+}
+
+/// Scale a byte count down through KB/MB/GB/TB, returning the reduced value
+/// and its unit. The memory, disk and network-rate modules all share this so
+/// the binary-prefix arithmetic lives in exactly one place.
+fn scale_bytes(bytes: f64) -> (f64, &'static str) {
+    let mut v = bytes;
+    let mut unit = "B";
+    for next in ["KB", "MB", "GB", "TB"] {
+        if v < 1024f64 {
+            break;
+        }
+        v /= 1024f64;
+        unit = next;
+    }
+    (v, unit)
+}
+
+fn fmt_rate(bytes_per_sec: f64) -> String {
+    let (v, unit) = scale_bytes(bytes_per_sec);
+    match unit {
+        "B" | "KB" => format!("{:.0}{}/s", v, unit),
+        _ => format!("{:.1}{}/s", v, unit),
+    }
+}
+
+fn load_net(status: &mut Status) {
+    status.sys_stat.refresh_networks();
+    let mut rx = 0;
+    let mut tx = 0;
+    for (name, data) in status.sys_stat.networks() {
+        if name == "lo" {
+            continue;
+        }
+        rx += data.total_received();
+        tx += data.total_transmitted();
+    }
+
+    let now = Instant::now();
+    // sysinfo's counters are cumulative, so the first sample only seeds the
+    // previous totals; emitting a rate here would divide the whole uptime's
+    // traffic by a few seconds and print an absurd spike.
+    if !status.net_seeded {
+        status.prev_rx = rx;
+        status.prev_tx = tx;
+        status.last_net_instant = now;
+        status.net_seeded = true;
+        return;
+    }
+
+    let secs = now.duration_since(status.last_net_instant).as_secs_f64();
+    let secs = if secs > 0f64 { secs } else { 1f64 };
+    let down = rx.saturating_sub(status.prev_rx) as f64 / secs;
+    let up = tx.saturating_sub(status.prev_tx) as f64 / secs;
+    status.prev_rx = rx;
+    status.prev_tx = tx;
+    status.last_net_instant = now;
+    status.net = format!("↓{} ↑{}", fmt_rate(down), fmt_rate(up));
+}
+
+/// A source of the current playback volume, rendered as the bar sees it:
+/// `"-/-"` when muted and `"NN%"` otherwise (`None` on a read error).
+trait VolumeBackend {
+    fn read(&mut self) -> Option<String>;
+    /// Descriptors to block on so volume changes are pushed the instant they
+    /// happen; empty when the backend has no pollable source, in which case
+    /// the caller just falls back to its fixed cadence.
+    fn poll_fds(&self) -> Vec<alsa::poll::pollfd> {
+        Vec::new()
+    }
+    /// Acknowledge pending descriptor events after a poll wakeup.
+    fn handle_events(&mut self) {}
+}
+
+/// Reads the ALSA `Master` simple element of the `default` card, averaging
+/// across the channels it exposes just like the original inline reader did.
+/// The `Mixer` is opened once and kept alive so its poll descriptors can drive
+/// push-style updates instead of reopening the device every tick.
+struct AlsaBackend {
+    mixer: Option<Mixer>,
+}
+
+impl AlsaBackend {
+    fn new() -> Self {
+        Self {
+            mixer: Mixer::new("default", false).ok(),
+        }
+    }
+}
+
+impl VolumeBackend for AlsaBackend {
+    fn read(&mut self) -> Option<String> {
+        if self.mixer.is_none() {
+            self.mixer = Mixer::new("default", false).ok();
+        }
+        let mixer = self.mixer.as_ref()?;
+        let master = mixer.find_selem(&SelemId::new("Master", 0))?;
+        let (vol_min, vol_max) = master.get_playback_volume_range();
+        let mut total = 0;
+        let mut cur = 0;
+        for c in SelemChannelId::all().iter() {
+            if master.has_playback_channel(*c) {
+                total += vol_max - vol_min;
+                let sw = master.get_playback_switch(*c).ok()?;
+                if sw == 0 {
+                    continue;
+                }
+                let vol = master.get_playback_volume(*c).ok()?;
+                cur += vol - vol_min;
+            }
+        }
+        Some(if cur == 0 {
+            "-/-".to_string()
+        } else {
+            format!("{:.0}%", (cur * 100) as f64 / total as f64)
+        })
+    }
+
+    fn poll_fds(&self) -> Vec<alsa::poll::pollfd> {
+        self.mixer
+            .as_ref()
+            .and_then(|m| alsa::poll::Descriptors::get(m).ok())
+            .unwrap_or_default()
+    }
+
+    fn handle_events(&mut self) {
+        if let Some(m) = self.mixer.as_ref() {
+            let _ = m.handle_events();
+        }
+    }
+}
+
+/// Talks to PulseAudio/PipeWire's pulse socket and reports the default sink's
+/// volume, which is what the user actually hears on those setups.
+struct PulseBackend {
+    mainloop: Mainloop,
+    context: Context,
+}
+
+impl PulseBackend {
+    fn new() -> Option<Self> {
+        let mut mainloop = Mainloop::new()?;
+        let mut context = Context::new(&mainloop, "dwm-status")?;
+        context
+            .connect(None, ContextFlagSet::NOFLAGS, None)
+            .ok()?;
+        loop {
+            match mainloop.iterate(true) {
+                IterateResult::Success(_) => {}
+                IterateResult::Quit(_) | IterateResult::Err(_) => return None,
+            }
+            match context.get_state() {
+                PulseState::Ready => break,
+                PulseState::Failed | PulseState::Terminated => return None,
+                _ => {}
+            }
+        }
+        Some(Self { mainloop, context })
+    }
+
+    /// Pump the mainloop until an async introspection callback fills `slot`,
+    /// yielding its value (or `None` if the connection dies meanwhile).
+    fn wait<T>(&mut self, slot: &Rc<RefCell<Option<T>>>) -> Option<T> {
+        while slot.borrow().is_none() {
+            match self.mainloop.iterate(true) {
+                IterateResult::Success(_) => {}
+                IterateResult::Quit(_) | IterateResult::Err(_) => return None,
+            }
+        }
+        slot.borrow_mut().take()
+    }
+}
+
+impl VolumeBackend for PulseBackend {
+    fn read(&mut self) -> Option<String> {
+        let name = Rc::new(RefCell::new(None));
+        {
+            let name = name.clone();
+            self.context.introspect().get_server_info(move |info| {
+                *name.borrow_mut() =
+                    Some(info.default_sink_name.as_ref().map(|n| n.to_string()));
+            });
+        }
+        let sink = self.wait(&name)??;
+
+        let result = Rc::new(RefCell::new(None));
+        {
+            let result = result.clone();
+            self.context
+                .introspect()
+                .get_sink_info_by_name(&sink, move |list| match list {
+                    ListResult::Item(item) => {
+                        let pct = item.volume.avg().0 as f64 / Volume::NORMAL.0 as f64 * 100f64;
+                        *result.borrow_mut() = Some(Some((pct, item.mute)));
+                    }
+                    ListResult::End | ListResult::Error => {
+                        let mut r = result.borrow_mut();
+                        if r.is_none() {
+                            *r = Some(None);
                         }
-                        let vol = master.get_playback_volume(*c).ok()?;
-                        cur += vol - vol_min;
                     }
-                }
-                Some(if cur == 0 {
-                    "-/-".to_string()
-                } else {
-                    format!("{:.0}%", (cur * 100) as f64 / total as f64)
-                })
-            })
-            .unwrap_or("E".to_string())
-    } else {
-        "E".to_string()
+                });
+        }
+        let (pct, mute) = self.wait(&result)??;
+        Some(if mute {
+            "-/-".to_string()
+        } else {
+            format!("{:.0}%", pct)
+        })
+    }
+
+    /// The standard pulse mainloop drives its own socket internally and does
+    /// not expose a descriptor we can hand to `alsa::poll`, so there is nothing
+    /// to block on; report none and let the main loop re-read us on the
+    /// configured volume cadence instead.
+    fn poll_fds(&self) -> Vec<alsa::poll::pollfd> {
+        Vec::new()
+    }
+}
+
+/// Prefer the pulse socket (PulseAudio/PipeWire) and fall back to raw ALSA,
+/// mirroring how audio actually routes on most desktops today.
+fn detect_volume_backend() -> Box<dyn VolumeBackend> {
+    match PulseBackend::new() {
+        Some(pulse) => Box::new(pulse),
+        None => Box::new(AlsaBackend::new()),
+    }
+}
+
+fn load_snd_vol(status: &mut Status) {
+    if let Some(backend) = status.vol_backend.as_mut() {
+        status.vol = backend.read().unwrap_or_else(|| "E".to_string());
+    }
+}
+
+fn fmt_size(bytes: u64) -> String {
+    let (v, unit) = scale_bytes(bytes as f64);
+    match unit {
+        "B" | "KB" | "MB" => format!("{:.0}{}", v, unit),
+        _ => format!("{:.1}{}", v, unit),
+    }
+}
+
+fn load_disk(status: &mut Status) {
+    status.sys_stat.refresh_disks();
+    let mount = status.cfg.disk_mount.as_str();
+    for disk in status.sys_stat.disks() {
+        if disk.mount_point().to_str() == Some(mount) {
+            let used = disk.total_space().saturating_sub(disk.available_space());
+            status.disk = fmt_size(used);
+            return;
+        }
+    }
+}
+
+fn load_thermal(status: &mut Status) {
+    status.sys_stat.refresh_components();
+    let components = status.sys_stat.components();
+    // Some machines expose no thermal sensors at all; leave the field empty
+    // rather than print a bogus reading. `{temp}` then renders as an empty
+    // string; the literal text around it in the format is still printed.
+    if components.is_empty() {
+        return;
+    }
+    let preferred = components.iter().find(|c| {
+        let label = c.label();
+        label.contains("Package id 0") || label.contains("coretemp") || label.contains("k10temp")
+    });
+    let temp = match preferred {
+        Some(c) => c.temperature(),
+        None => components
+            .iter()
+            .map(|c| c.temperature())
+            .fold(f32::MIN, f32::max),
     };
+    status.temp = format!("{:.0}°C", temp);
 }
 
-fn load_sys_stat(status: &mut Status) {
-    status.sys_stat.refresh_system();
+/// Eight block-height glyphs, lowest load first, used by the per-core bars.
+const CPU_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+fn load_cpu(status: &mut Status) {
+    status.sys_stat.refresh_cpu();
     status.cpu = format!("{:2.0}%", status.sys_stat.global_cpu_info().cpu_usage());
+    status.cpu_bars = status
+        .sys_stat
+        .cpus()
+        .iter()
+        .map(|cpu| {
+            let bucket = (cpu.cpu_usage() / 100f32 * 7f32).round() as usize;
+            CPU_BARS[bucket.min(CPU_BARS.len() - 1)]
+        })
+        .collect();
+}
 
-    status.mem = || -> String {
-        let mut used = status.sys_stat.used_memory();
-        let mut unit = "KB";
-        if used > 1024 {
-            used /= 1024;
-            unit = "MB";
-            if used >= 1000 {
-                return format!("{:.1}GB", used as f64 / 1024f64);
-            }
-        }
-        format!("{:3}{}", used, unit)
-    }();
+fn load_mem(status: &mut Status) {
+    status.sys_stat.refresh_memory();
+    let (v, unit) = scale_bytes(status.sys_stat.used_memory() as f64);
+    status.mem = match unit {
+        "B" | "KB" | "MB" => format!("{:3.0}{}", v, unit),
+        _ => format!("{:.1}{}", v, unit),
+    };
+}
+
+/// Next deadline for a module whose output is a wall-clock quantity (the
+/// clock): snap to the next interval boundary so the shown value rolls over in
+/// step with reality rather than drifting by the startup phase. A zero
+/// interval degenerates to "every tick".
+fn aligned_due(now_uts: i64, interval: u64) -> i64 {
+    let interval = interval as i64;
+    if interval <= 0 {
+        return now_uts;
+    }
+    now_uts - now_uts.rem_euclid(interval) + interval
 }
 
 fn refresh_status(status: &mut Status, force: bool) {
     let now = chrono::Local::now();
     let now_uts = now.timestamp();
-    if force || now_uts % 60 == 0 {
-        status.time = now.format("%m/%d %H:%M").to_string();
+
+    if status.uses(Token::Time) && (force || now_uts >= status.due.time) {
+        status.time = now.format(&status.time_fmt).to_string();
+        status.due.time = aligned_due(now_uts, status.cfg.intervals.time);
         status.should_update = true;
     }
-    if FULL_STATUS.load(Ordering::SeqCst) {
+    if (status.uses(Token::Cpu) || status.uses(Token::CpuBars))
+        && (force || now_uts >= status.due.cpu)
+    {
+        load_cpu(status);
+        status.due.cpu = now_uts + status.cfg.intervals.cpu as i64;
+        status.should_update = true;
+    }
+    if status.uses(Token::Mem) && (force || now_uts >= status.due.mem) {
+        load_mem(status);
+        status.due.mem = now_uts + status.cfg.intervals.mem as i64;
+        status.should_update = true;
+    }
+    if status.uses(Token::Net) && (force || now_uts >= status.due.net) {
+        load_net(status);
+        status.due.net = now_uts + status.cfg.intervals.net as i64;
+        status.should_update = true;
+    }
+    if status.uses(Token::Temp) && (force || now_uts >= status.due.temp) {
+        load_thermal(status);
+        status.due.temp = now_uts + status.cfg.intervals.temp as i64;
+        status.should_update = true;
+    }
+    if status.uses(Token::Disk) && (force || now_uts >= status.due.disk) {
+        load_disk(status);
+        status.due.disk = now_uts + status.cfg.intervals.disk as i64;
+        status.should_update = true;
+    }
+    // Volume is normally refreshed event-driven from the main loop via the
+    // backend's poll descriptors. A backend with no pollable fds (e.g. the
+    // Pulse path) would otherwise never be re-read, so fall back to the
+    // configured cadence for it here.
+    let no_fds = status
+        .vol_backend
+        .as_ref()
+        .is_some_and(|b| b.poll_fds().is_empty());
+    if status.uses(Token::Vol) && no_fds && (force || now_uts >= status.due.vol) {
         load_snd_vol(status);
+        status.due.vol = now_uts + status.cfg.intervals.vol as i64;
         status.should_update = true;
     }
-    if force || now_uts % 3 == 0 {
-        load_sys_stat(status);
+    if FULL_STATUS.load(Ordering::SeqCst) {
+        // Re-render so the full-status layout stays current.
         status.should_update = true;
     }
 }
@@ -148,28 +700,74 @@ fn main() {
         sys::signal(SIGUSR1, sig_user as sighandler_t);
     }
 
+    let cfg = config::load();
+    let fmt_basic = parse_format(&cfg.format);
+    let fmt_full = parse_format(&cfg.format_full);
+    let time_fmt = resolve_time_fmt(&[&fmt_basic, &fmt_full]);
+
+    // Only connect to a sound server if some format actually shows `{vol}`.
+    let needs_vol = uses_token(&fmt_basic, Token::Vol) || uses_token(&fmt_full, Token::Vol);
+    let vol_backend = needs_vol.then(detect_volume_backend);
+
     let mut status = Status {
         time: String::default(),
         cpu: String::default(),
         mem: String::default(),
         sys_stat: System::new(),
         vol: String::default(),
+        vol_backend,
+        cpu_bars: String::default(),
+        net: String::default(),
+        temp: String::default(),
+        disk: String::default(),
+        prev_rx: 0,
+        prev_tx: 0,
+        last_net_instant: Instant::now(),
+        net_seeded: false,
+        cfg,
+        fmt_basic,
+        fmt_full,
+        time_fmt,
+        due: Due::default(),
         should_update: false,
     };
+    status.sys_stat.refresh_networks_list();
+    status.sys_stat.refresh_components_list();
+    status.sys_stat.refresh_disks_list();
     refresh_status(&mut status, true);
+    // Seed the cached volume once so it is ready the moment full status turns
+    // on, independent of any later descriptor events. No-op when `{vol}` is
+    // unused and no backend was connected.
+    load_snd_vol(&mut status);
     loop {
         if status.should_update {
-            if FULL_STATUS.load(Ordering::SeqCst) {
-                update_status_text(format!(
-                    "[{}|{}] ({}) {}",
-                    status.cpu, status.mem, status.vol, status.time
-                ));
+            let segs = if FULL_STATUS.load(Ordering::SeqCst) {
+                &status.fmt_full
             } else {
-                update_status_text(format!("[{}|{}] {}", status.cpu, status.mem, status.time));
-            }
+                &status.fmt_basic
+            };
+            update_status_text(status.render(segs));
             status.should_update = false;
         }
-        thread::sleep(Duration::from_secs(1));
+
+        // Block on the volume backend's descriptors, but no longer than a
+        // second so the clock/stat cadence below still runs on time. A wakeup
+        // with activity means the user just changed the volume, so re-read it
+        // immediately regardless of whether full status is showing.
+        let mut fds = status
+            .vol_backend
+            .as_ref()
+            .map(|b| b.poll_fds())
+            .unwrap_or_default();
+        if fds.is_empty() {
+            thread::sleep(Duration::from_secs(1));
+        } else if matches!(alsa::poll::poll(&mut fds, 1000), Ok(n) if n > 0) {
+            if let Some(b) = status.vol_backend.as_mut() {
+                b.handle_events();
+            }
+            load_snd_vol(&mut status);
+            status.should_update = true;
+        }
         refresh_status(&mut status, false);
     }
 }